@@ -1,14 +1,17 @@
 use rand::seq::SliceRandom;
-use serde_derive::Deserialize;
+use serde_derive::{Deserialize, Serialize};
+use std::collections::{HashMap, HashSet};
 use std::env;
 use std::fmt;
 use std::fs::File;
+use std::path::Path;
 use std::{io, io::BufReader};
 
 #[derive(Debug)]
 enum QuizParseError {
     FileNotFound(String),
     ParseError(String),
+    Multiple(Vec<(String, QuizParseError)>),
 }
 
 impl fmt::Display for QuizParseError {
@@ -16,6 +19,12 @@ impl fmt::Display for QuizParseError {
         match *self {
             Self::FileNotFound(ref s) => write!(f, "Error reading json file: {}", s),
             Self::ParseError(ref s) => write!(f, "Parse Error: {}", s),
+            Self::Multiple(ref errors) => {
+                for (file_name, error) in errors {
+                    writeln!(f, "{}: {}", file_name, error)?;
+                }
+                Ok(())
+            }
         }
     }
 }
@@ -50,6 +59,7 @@ impl Answer {
 struct Question {
     question: String,
     answers: Vec<Answer>,
+    source: Option<String>,
 }
 
 #[derive(Debug)]
@@ -57,15 +67,307 @@ struct Quiz {
     questions: Vec<Question>,
 }
 
+// Strips `#`/`//`/`/* */` comments and quotes bare keys and unquoted
+// single-line string values, producing text `serde_json` can read.
+fn normalize_hjson(input: &str) -> String {
+    #[derive(Clone, Copy, PartialEq)]
+    enum Ctx {
+        ObjKey,
+        ObjVal,
+        ArrVal,
+    }
+
+    // Copies whitespace, and the newlines inside block comments, into `out`
+    // (to keep line numbers aligned) while dropping comment text, and
+    // returns the index of the next non-ignorable character.
+    fn skip_ignorable(bytes: &[char], mut i: usize, out: &mut String) -> usize {
+        let n = bytes.len();
+        loop {
+            if i < n && bytes[i].is_whitespace() {
+                out.push(bytes[i]);
+                i += 1;
+            } else if i < n && bytes[i] == '#' {
+                while i < n && bytes[i] != '\n' {
+                    i += 1;
+                }
+            } else if i + 1 < n && bytes[i] == '/' && bytes[i + 1] == '/' {
+                i += 2;
+                while i < n && bytes[i] != '\n' {
+                    i += 1;
+                }
+            } else if i + 1 < n && bytes[i] == '/' && bytes[i + 1] == '*' {
+                i += 2;
+                while i + 1 < n && !(bytes[i] == '*' && bytes[i + 1] == '/') {
+                    if bytes[i] == '\n' {
+                        out.push('\n');
+                    }
+                    i += 1;
+                }
+                i = (i + 2).min(n);
+            } else {
+                return i;
+            }
+        }
+    }
+
+    fn is_json_literal(s: &str) -> bool {
+        s == "true" || s == "false" || s == "null" || s.parse::<f64>().is_ok()
+    }
+
+    fn quote(raw: &str, out: &mut String) {
+        out.push('"');
+        out.push_str(&raw.replace('\\', "\\\\").replace('"', "\\\""));
+        out.push('"');
+    }
+
+    // An object that was awaiting a value goes back to awaiting a key.
+    fn flip_obj_val(stack: &mut [Ctx]) {
+        if let Some(top @ Ctx::ObjVal) = stack.last_mut() {
+            *top = Ctx::ObjKey;
+        }
+    }
+
+    // A newline can stand in for the comma between members; add one if
+    // the next significant character isn't already `,`, `}` or `]`.
+    fn close_value(bytes: &[char], i: usize, stack: &mut [Ctx], out: &mut String) -> usize {
+        flip_obj_val(stack);
+        let next = skip_ignorable(bytes, i, out);
+        if next < bytes.len() && !matches!(bytes[next], ',' | '}' | ']') {
+            out.push(',');
+        }
+        next
+    }
+
+    let bytes: Vec<char> = input.chars().collect();
+    let n = bytes.len();
+    let mut out = String::with_capacity(input.len());
+    let mut stack: Vec<Ctx> = Vec::new();
+    // Most recently read key at each nesting depth (`None` for array
+    // depths). Only a bare `answer` value may become a raw JSON literal;
+    // every other bare value, including array entries, is quoted.
+    let mut current_key: Vec<Option<String>> = Vec::new();
+    let mut i = 0;
+
+    loop {
+        i = skip_ignorable(&bytes, i, &mut out);
+        if i >= n {
+            break;
+        }
+        let c = bytes[i];
+        match c {
+            '{' => {
+                out.push(c);
+                stack.push(Ctx::ObjKey);
+                current_key.push(None);
+                i += 1;
+            }
+            '[' => {
+                out.push(c);
+                stack.push(Ctx::ArrVal);
+                current_key.push(None);
+                i += 1;
+            }
+            '}' | ']' => {
+                out.push(c);
+                stack.pop();
+                current_key.pop();
+                i = close_value(&bytes, i + 1, &mut stack, &mut out);
+            }
+            ':' => {
+                out.push(c);
+                if let Some(top @ Ctx::ObjKey) = stack.last_mut() {
+                    *top = Ctx::ObjVal;
+                }
+                i += 1;
+            }
+            ',' => {
+                // Trailing comma: only keep it if a value follows before
+                // the container closes.
+                let next = skip_ignorable(&bytes, i + 1, &mut out);
+                if next < n && bytes[next] != '}' && bytes[next] != ']' {
+                    out.push(',');
+                    flip_obj_val(&mut stack);
+                }
+                i = next;
+            }
+            '"' => {
+                let is_key = matches!(stack.last(), Some(Ctx::ObjKey));
+                out.push(c);
+                i += 1;
+                let key_start = i;
+                while i < n {
+                    let ch = bytes[i];
+                    out.push(ch);
+                    i += 1;
+                    if ch == '\\' && i < n {
+                        out.push(bytes[i]);
+                        i += 1;
+                        continue;
+                    }
+                    if ch == '"' {
+                        break;
+                    }
+                }
+                if is_key {
+                    let key: String = bytes[key_start..i.saturating_sub(1)].iter().collect();
+                    if let Some(slot) = current_key.last_mut() {
+                        *slot = Some(key);
+                    }
+                } else {
+                    i = close_value(&bytes, i, &mut stack, &mut out);
+                }
+            }
+            _ => {
+                let is_key = matches!(stack.last(), Some(Ctx::ObjKey));
+                let start = i;
+                if is_key {
+                    while i < n && bytes[i] != ':' && bytes[i] != '\n' {
+                        i += 1;
+                    }
+                    let raw: String = bytes[start..i].iter().collect();
+                    let key = raw.trim().to_string();
+                    quote(&key, &mut out);
+                    if let Some(slot) = current_key.last_mut() {
+                        *slot = Some(key);
+                    }
+                } else {
+                    while i < n && !matches!(bytes[i], ',' | '}' | ']' | '\n') {
+                        i += 1;
+                    }
+                    let raw: String = bytes[start..i].iter().collect();
+                    let value = raw.trim_end();
+                    let is_answer_value = matches!(stack.last(), Some(Ctx::ObjVal))
+                        && current_key.last().and_then(|key| key.as_deref()) == Some("answer");
+                    if is_answer_value && is_json_literal(value) {
+                        out.push_str(value);
+                    } else {
+                        quote(value, &mut out);
+                    }
+                    i = close_value(&bytes, i, &mut stack, &mut out);
+                }
+            }
+        }
+    }
+
+    out
+}
+
+// Each question is a `## <question text>` heading followed by one
+// `- [x]`/`- [ ]` option per line, blocks separated by a blank line.
+fn parse_markdown(contents: &str) -> Result<Vec<Question>, QuizParseError> {
+    let mut questions = Vec::new();
+    let mut lines = contents.lines().enumerate().peekable();
+
+    while let Some((line_no, line)) = lines.next() {
+        if line.trim().is_empty() {
+            continue;
+        }
+
+        let Some(question_text) = line.trim().strip_prefix("## ") else {
+            return Err(QuizParseError::ParseError(format!(
+                "line {}: expected a '## <question>' heading, found {:?}",
+                line_no + 1,
+                line
+            )));
+        };
+
+        let mut answers = Vec::new();
+        while let Some(&(_, next_line)) = lines.peek() {
+            if next_line.trim().is_empty() {
+                break;
+            }
+            let (option_line_no, next_line) = lines.next().unwrap();
+            let trimmed = next_line.trim();
+            if let Some(option) = trimmed.strip_prefix("- [x] ") {
+                answers.push(Answer::CorrectAnswer(option.to_string()));
+            } else if let Some(option) = trimmed.strip_prefix("- [ ] ") {
+                answers.push(Answer::IncorrectAnswer(option.to_string()));
+            } else {
+                return Err(QuizParseError::ParseError(format!(
+                    "line {}: expected a '- [x]' or '- [ ]' option, found {:?}",
+                    option_line_no + 1,
+                    next_line
+                )));
+            }
+        }
+
+        let correct_count = answers
+            .iter()
+            .filter(|answer| matches!(answer, Answer::CorrectAnswer(_)))
+            .count();
+        if correct_count != 1 {
+            return Err(QuizParseError::ParseError(format!(
+                "question {:?} (line {}): expected exactly one '- [x]' option, found {}",
+                question_text,
+                line_no + 1,
+                correct_count
+            )));
+        }
+
+        questions.push(Question {
+            question: question_text.to_string(),
+            answers,
+            source: None,
+        });
+    }
+
+    Ok(questions)
+}
+
+fn parse_json_reader(file_name: &str) -> Result<JsonQuiz, QuizParseError> {
+    let file = File::open(file_name).map_err(|e| QuizParseError::FileNotFound(e.to_string()))?;
+    serde_json::from_reader(BufReader::new(file))
+        .map_err(|e| QuizParseError::ParseError(e.to_string()))
+}
+
+// simd-json parses in place, so it needs an owned, mutable, padded buffer
+// rather than a `Read`.
+#[cfg(feature = "simd-json")]
+fn parse_json_simd(mut bytes: Vec<u8>) -> Result<JsonQuiz, QuizParseError> {
+    simd_json::serde::from_slice(&mut bytes).map_err(|e| QuizParseError::ParseError(e.to_string()))
+}
+
+fn parse_json(file_name: &str) -> Result<JsonQuiz, QuizParseError> {
+    #[cfg(feature = "simd-json")]
+    {
+        match std::fs::read(file_name) {
+            Ok(bytes) => parse_json_simd(bytes).or_else(|_| parse_json_reader(file_name)),
+            Err(e) => Err(QuizParseError::FileNotFound(e.to_string())),
+        }
+    }
+    #[cfg(not(feature = "simd-json"))]
+    {
+        parse_json_reader(file_name)
+    }
+}
+
 impl TryFrom<&str> for Quiz {
     type Error = QuizParseError;
 
     fn try_from(file_name: &str) -> Result<Self, QuizParseError> {
-        let file =
-            File::open(file_name).map_err(|e| QuizParseError::FileNotFound(e.to_string()))?;
+        let extension = Path::new(file_name)
+            .extension()
+            .and_then(|ext| ext.to_str())
+            .unwrap_or_default()
+            .to_ascii_lowercase();
 
-        let json_quiz: JsonQuiz = serde_json::from_reader(BufReader::new(file))
-            .map_err(|e| QuizParseError::ParseError(e.to_string()))?;
+        if extension == "md" || extension == "txt" {
+            let contents = std::fs::read_to_string(file_name)
+                .map_err(|e| QuizParseError::FileNotFound(e.to_string()))?;
+            return Ok(Quiz {
+                questions: parse_markdown(&contents)?,
+            });
+        }
+
+        let json_quiz: JsonQuiz = if extension == "hjson" {
+            let contents = std::fs::read_to_string(file_name)
+                .map_err(|e| QuizParseError::FileNotFound(e.to_string()))?;
+            let normalized = normalize_hjson(&contents);
+            serde_json::from_str(&normalized)
+                .map_err(|e| QuizParseError::ParseError(e.to_string()))?
+        } else {
+            parse_json(file_name)?
+        };
 
         Ok(Quiz {
             questions: json_quiz
@@ -85,12 +387,45 @@ impl TryFrom<&str> for Quiz {
                             }
                         })
                         .collect(),
+                    source: None,
                 })
                 .collect(),
         })
     }
 }
 
+// Merges the questions from several quiz files into one `Quiz`.
+struct Loader {
+    file_names: Vec<String>,
+}
+
+impl Loader {
+    fn new(file_names: Vec<String>) -> Self {
+        Loader { file_names }
+    }
+
+    fn load(&self) -> Result<Quiz, QuizParseError> {
+        let mut questions = Vec::new();
+        let mut errors = Vec::new();
+
+        for file_name in &self.file_names {
+            match Quiz::try_from(file_name.as_str()) {
+                Ok(quiz) => questions.extend(quiz.questions.into_iter().map(|question| Question {
+                    source: Some(file_name.clone()),
+                    ..question
+                })),
+                Err(e) => errors.push((file_name.clone(), e)),
+            }
+        }
+
+        if !errors.is_empty() {
+            return Err(QuizParseError::Multiple(errors));
+        }
+
+        Ok(Quiz { questions })
+    }
+}
+
 #[derive(Debug, Default)]
 struct Results {
     correct: usize,
@@ -130,35 +465,242 @@ fn get_correct_answer_index(answers: &[Answer]) -> usize {
         .unwrap_or_else(|| panic!("Correct answer not found"))
 }
 
-fn display_results(results: &Results) -> String {
+fn percent_correct(results: &Results) -> usize {
     let total = results.correct + results.incorrect;
-    let percent = match total {
+    match total {
         0 => 0,
         _ => 100 * results.correct / (total),
+    }
+}
+
+fn display_results(results: &Results) -> String {
+    format!(
+        "{}% correct ({} of {})",
+        percent_correct(results),
+        results.correct,
+        results.correct + results.incorrect
+    )
+}
+
+#[derive(Debug, Serialize)]
+struct QuestionResult {
+    question: String,
+    source: Option<String>,
+    chosen: String,
+    correct: String,
+    is_correct: bool,
+}
+
+#[derive(Debug, Serialize)]
+struct ResultsReport {
+    correct: usize,
+    incorrect: usize,
+    percent: usize,
+    questions: Vec<QuestionResult>,
+}
+
+impl ResultsReport {
+    fn new(results: &Results, questions: Vec<QuestionResult>) -> Self {
+        ResultsReport {
+            correct: results.correct,
+            incorrect: results.incorrect,
+            percent: percent_correct(results),
+            questions,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum OutputFormat {
+    Human,
+    Json,
+}
+
+fn extract_flag_value(args: &mut Vec<String>, flag: &str) -> Result<Option<String>, String> {
+    let Some(flag_index) = args.iter().position(|a| a == flag) else {
+        return Ok(None);
     };
-    format!("{}% correct ({} of {})", percent, results.correct, total).to_string()
+    args.remove(flag_index);
+    if flag_index >= args.len() {
+        return Err(format!("Error: {} requires a value", flag));
+    }
+    Ok(Some(args.remove(flag_index)))
 }
 
-fn get_file_name_from_args(args: Vec<String>) -> Result<String, String> {
-    args.get(1)
-        .map(|s| s.to_string())
-        .ok_or_else(|| "Error: Please input one parameter for json filename".to_string())
+fn get_output_format_from_args(args: &mut Vec<String>) -> Result<OutputFormat, String> {
+    match extract_flag_value(args, "--format")?.as_deref() {
+        None => Ok(OutputFormat::Human),
+        Some("json") => Ok(OutputFormat::Json),
+        Some(other) => Err(format!("Error: unknown format '{}'", other)),
+    }
+}
+
+fn get_file_name_from_args(args: Vec<String>) -> Result<Vec<String>, String> {
+    let file_names = args[1..].to_vec();
+    if file_names.is_empty() {
+        return Err("Error: Please input at least one parameter for quiz filename".to_string());
+    }
+    Ok(file_names)
+}
+
+struct SessionArgs {
+    log_path: Option<String>,
+    resuming: bool,
+}
+
+fn get_session_args_from_args(args: &mut Vec<String>) -> Result<SessionArgs, String> {
+    let resume_path = extract_flag_value(args, "--resume")?;
+    let log_path = extract_flag_value(args, "--log")?;
+
+    match (resume_path, log_path) {
+        (Some(_), Some(_)) => Err("Error: --log and --resume are mutually exclusive".to_string()),
+        (Some(path), None) => Ok(SessionArgs {
+            log_path: Some(path),
+            resuming: true,
+        }),
+        (None, Some(path)) => Ok(SessionArgs {
+            log_path: Some(path),
+            resuming: false,
+        }),
+        (None, None) => Ok(SessionArgs {
+            log_path: None,
+            resuming: false,
+        }),
+    }
+}
+
+fn question_id(question: &str) -> u64 {
+    use std::hash::{Hash, Hasher};
+    let mut hasher = std::collections::hash_map::DefaultHasher::new();
+    question.hash(&mut hasher);
+    hasher.finish()
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct SessionRecord {
+    question_id: u64,
+    chosen: String,
+    correct: String,
+    is_correct: bool,
+}
+
+struct SessionLog {
+    writer: io::BufWriter<File>,
+}
+
+impl SessionLog {
+    fn start(path: &str) -> io::Result<Self> {
+        Ok(SessionLog {
+            writer: io::BufWriter::new(File::create(path)?),
+        })
+    }
+
+    fn resume(path: &str) -> io::Result<Self> {
+        Ok(SessionLog {
+            writer: io::BufWriter::new(File::options().append(true).open(path)?),
+        })
+    }
+
+    fn append(&mut self, record: &SessionRecord) -> io::Result<()> {
+        use std::io::Write;
+
+        serde_json::to_writer(&mut self.writer, record).map_err(io::Error::from)?;
+        self.writer.write_all(b"\n")?;
+        self.writer.flush()
+    }
+
+    fn load(path: &str) -> io::Result<Vec<SessionRecord>> {
+        let contents = std::fs::read_to_string(path)?;
+        contents
+            .lines()
+            .filter(|line| !line.trim().is_empty())
+            .map(|line| serde_json::from_str(line).map_err(io::Error::from))
+            .collect()
+    }
 }
 
 fn main() {
-    let questions_file = get_file_name_from_args(env::args().collect()).unwrap_or_else(|e| {
+    let mut args: Vec<String> = env::args().collect();
+    let format = get_output_format_from_args(&mut args).unwrap_or_else(|e| {
+        println!("{}", e);
+        std::process::exit(1);
+    });
+    let session_args = get_session_args_from_args(&mut args).unwrap_or_else(|e| {
         println!("{}", e);
         std::process::exit(1);
     });
 
-    let quiz = Quiz::try_from(questions_file.as_str()).unwrap_or_else(|e| {
+    let questions_files = get_file_name_from_args(args).unwrap_or_else(|e| {
         println!("{}", e);
         std::process::exit(1);
     });
 
+    let quiz = Loader::new(questions_files).load().unwrap_or_else(|e| {
+        println!("{}", e);
+        std::process::exit(1);
+    });
+
+    let id_lookup: HashMap<u64, (String, Option<String>)> = quiz
+        .questions
+        .iter()
+        .map(|question| {
+            (
+                question_id(&question.question),
+                (question.question.clone(), question.source.clone()),
+            )
+        })
+        .collect();
+
     let mut results = Results::default();
+    let mut answered = HashSet::new();
+    let mut question_results = Vec::new();
+
+    if let Some(path) = session_args
+        .log_path
+        .as_ref()
+        .filter(|_| session_args.resuming)
+    {
+        let records = SessionLog::load(path).unwrap_or_else(|e| {
+            println!("Error resuming session log: {}", e);
+            std::process::exit(1);
+        });
+
+        for record in records {
+            if record.is_correct {
+                results.correct += 1;
+            } else {
+                results.incorrect += 1;
+            }
+            answered.insert(record.question_id);
+
+            let (question, source) = id_lookup
+                .get(&record.question_id)
+                .cloned()
+                .unwrap_or_default();
+            question_results.push(QuestionResult {
+                question,
+                source,
+                chosen: record.chosen,
+                correct: record.correct,
+                is_correct: record.is_correct,
+            });
+        }
+    }
+
+    let mut session_log = session_args.log_path.as_ref().map(|path| {
+        let log = if session_args.resuming {
+            SessionLog::resume(path)
+        } else {
+            SessionLog::start(path)
+        };
+        log.unwrap_or_else(|e| {
+            println!("Error opening session log: {}", e);
+            std::process::exit(1);
+        })
+    });
 
     let mut questions = quiz.questions;
+    questions.retain(|question| !answered.contains(&question_id(&question.question)));
     questions.shuffle(&mut rand::thread_rng());
 
     for question in questions {
@@ -168,8 +710,10 @@ fn main() {
         println!("{}", display_question(&question.question, &answers));
 
         let correct_answer_index = get_correct_answer_index(&answers);
+        let chosen_answer_index = get_user_answer_index(&answers);
+        let is_correct = chosen_answer_index == correct_answer_index;
 
-        if get_user_answer_index(&answers) == correct_answer_index {
+        if is_correct {
             results.correct += 1;
             println!("Correct!");
         } else {
@@ -180,9 +724,41 @@ fn main() {
             );
         }
 
+        let chosen = answers[chosen_answer_index].as_str().to_string();
+        let correct = answers[correct_answer_index].as_str().to_string();
+
+        if let Some(log) = &mut session_log {
+            log.append(&SessionRecord {
+                question_id: question_id(&question.question),
+                chosen: chosen.clone(),
+                correct: correct.clone(),
+                is_correct,
+            })
+            .unwrap_or_else(|e| {
+                println!("Error writing session log: {}", e);
+                std::process::exit(1);
+            });
+        }
+
+        question_results.push(QuestionResult {
+            question: question.question.clone(),
+            source: question.source.clone(),
+            chosen,
+            correct,
+            is_correct,
+        });
+
         println!("\n{}\n\n", display_results(&results));
     }
     println!("Done!");
+
+    if format == OutputFormat::Json {
+        let report = ResultsReport::new(&results, question_results);
+        serde_json::to_writer(io::stdout(), &report).unwrap_or_else(|e| {
+            println!("Error writing results report: {}", e);
+            std::process::exit(1);
+        });
+    }
 }
 
 #[cfg(test)]
@@ -221,7 +797,7 @@ mod test {
 
     #[test]
     fn test_quiz_try_from() {
-        let quiz = Quiz::try_from("example_json.txt").unwrap();
+        let quiz = Quiz::try_from("example.json").unwrap();
         let question = quiz.questions.first().unwrap();
         assert_eq!(question.question, "What is 10+10?");
 
@@ -240,6 +816,134 @@ mod test {
         };
     }
 
+    #[cfg(feature = "simd-json")]
+    #[test]
+    fn test_parse_json_simd_parses_valid_json() {
+        let bytes = br#"{"questions":[{"question":"Q","answer":1,"options":["a","b"]}]}"#.to_vec();
+        let json_quiz = parse_json_simd(bytes).unwrap();
+        assert_eq!(json_quiz.questions[0].question, "Q");
+        assert_eq!(json_quiz.questions[0].options, vec!["a", "b"]);
+    }
+
+    #[cfg(feature = "simd-json")]
+    #[test]
+    fn test_parse_json_simd_rejects_invalid_json() {
+        let bytes = b"not json".to_vec();
+        match parse_json_simd(bytes) {
+            Ok(_) => panic!("This should have failed to parse"),
+            Err(e) => assert!(matches!(e, QuizParseError::ParseError(_))),
+        };
+    }
+
+    #[cfg(feature = "simd-json")]
+    #[test]
+    fn test_parse_json_falls_back_to_reader_on_simd_failure() {
+        // Same `.or_else` composition `parse_json` uses: if the fast path
+        // can't parse the bytes, fall back to the reader-based path.
+        let result =
+            parse_json_simd(b"not json".to_vec()).or_else(|_| parse_json_reader("example.json"));
+        assert!(result.is_ok());
+    }
+
+    #[test]
+    fn test_quiz_try_from_hjson() {
+        let quiz = Quiz::try_from("example.hjson").unwrap();
+        let question = quiz.questions.first().unwrap();
+        assert_eq!(question.question, "What is 10+10?");
+
+        let answers = &question.answers;
+        assert_eq!(answers[0].as_str(), "10");
+        assert_eq!(answers[1].as_str(), "twenty");
+        assert_eq!(answers[2].as_str(), "30");
+        assert!(matches!(answers[0], Answer::IncorrectAnswer(_)));
+        assert!(matches!(answers[1], Answer::CorrectAnswer(_)));
+        assert!(matches!(answers[2], Answer::IncorrectAnswer(_)));
+    }
+
+    #[test]
+    fn test_quiz_try_from_hjson_numeric_options() {
+        // Unquoted options that look like numbers must still deserialize as
+        // strings; only a bare `answer` value is allowed to become a raw
+        // JSON literal.
+        let quiz = Quiz::try_from("example_numeric_options.hjson").unwrap();
+        let question = quiz.questions.first().unwrap();
+
+        let answers = &question.answers;
+        assert_eq!(answers[0].as_str(), "10");
+        assert_eq!(answers[1].as_str(), "20");
+        assert_eq!(answers[2].as_str(), "30");
+        assert!(matches!(answers[0], Answer::IncorrectAnswer(_)));
+        assert!(matches!(answers[1], Answer::CorrectAnswer(_)));
+        assert!(matches!(answers[2], Answer::IncorrectAnswer(_)));
+    }
+
+    #[test]
+    fn test_quiz_try_from_hjson_compact() {
+        // A bare value sharing a line with a following `,`, `}`, or `]`
+        // must not swallow that delimiter into the quoted string.
+        let quiz = Quiz::try_from("example_compact.hjson").unwrap();
+        let question = quiz.questions.first().unwrap();
+        assert_eq!(question.question, "What is 10+10?");
+
+        let answers = &question.answers;
+        assert_eq!(answers[0].as_str(), "10");
+        assert_eq!(answers[1].as_str(), "20");
+        assert_eq!(answers[2].as_str(), "30");
+        assert!(matches!(answers[0], Answer::IncorrectAnswer(_)));
+        assert!(matches!(answers[1], Answer::CorrectAnswer(_)));
+        assert!(matches!(answers[2], Answer::IncorrectAnswer(_)));
+    }
+
+    #[test]
+    fn test_quiz_try_from_markdown() {
+        let quiz = Quiz::try_from("example.md").unwrap();
+        let question = quiz.questions.first().unwrap();
+        assert_eq!(question.question, "What is 10+10?");
+
+        let answers = &question.answers;
+        assert_eq!(answers[0].as_str(), "10");
+        assert_eq!(answers[1].as_str(), "20");
+        assert_eq!(answers[2].as_str(), "30");
+        assert!(matches!(answers[0], Answer::IncorrectAnswer(_)));
+        assert!(matches!(answers[1], Answer::CorrectAnswer(_)));
+        assert!(matches!(answers[2], Answer::IncorrectAnswer(_)));
+    }
+
+    #[test]
+    fn test_quiz_try_from_plaintext() {
+        // `.txt` is the other extension the markdown/plaintext format is
+        // named for, alongside `.md`.
+        let quiz = Quiz::try_from("example.txt").unwrap();
+        let question = quiz.questions.first().unwrap();
+        assert_eq!(question.question, "What is 10+10?");
+
+        let answers = &question.answers;
+        assert_eq!(answers[0].as_str(), "10");
+        assert_eq!(answers[1].as_str(), "20");
+        assert_eq!(answers[2].as_str(), "30");
+        assert!(matches!(answers[0], Answer::IncorrectAnswer(_)));
+        assert!(matches!(answers[1], Answer::CorrectAnswer(_)));
+        assert!(matches!(answers[2], Answer::IncorrectAnswer(_)));
+    }
+
+    #[test]
+    fn test_parse_markdown_requires_exactly_one_correct_answer() {
+        let contents = "## What is 10+10?\n- [ ] 10\n- [ ] 20\n";
+        match parse_markdown(contents) {
+            Ok(_) => panic!("This should have failed to find a correct answer"),
+            Err(e) => assert!(matches!(e, QuizParseError::ParseError(_))),
+        };
+    }
+
+    #[test]
+    fn test_parse_markdown_rejects_malformed_option_line() {
+        let contents = "## What is 10+10?\n- 20\n";
+        match parse_markdown(contents) {
+            Ok(_) => panic!("This should have failed on the malformed option line"),
+            Err(e) => assert!(matches!(e, QuizParseError::ParseError(_))),
+        };
+    }
+
     #[test]
     fn test_display_question() {
         let display_str = display_question(
@@ -275,22 +979,263 @@ mod test {
         );
     }
 
+    #[test]
+    fn test_get_output_format_from_args() {
+        let mut args = vec!["script".to_string(), "filename.json".to_string()];
+        assert_eq!(
+            get_output_format_from_args(&mut args),
+            Ok(OutputFormat::Human)
+        );
+        assert_eq!(
+            args,
+            vec!["script".to_string(), "filename.json".to_string()]
+        );
+
+        let mut args = vec![
+            "script".to_string(),
+            "--format".to_string(),
+            "json".to_string(),
+            "filename.json".to_string(),
+        ];
+        assert_eq!(
+            get_output_format_from_args(&mut args),
+            Ok(OutputFormat::Json)
+        );
+        assert_eq!(
+            args,
+            vec!["script".to_string(), "filename.json".to_string()]
+        );
+
+        let mut args = vec![
+            "script".to_string(),
+            "--format".to_string(),
+            "yaml".to_string(),
+        ];
+        assert!(get_output_format_from_args(&mut args).is_err());
+    }
+
+    #[test]
+    fn test_results_report_serializes_to_json() {
+        let results = Results {
+            correct: 1,
+            incorrect: 1,
+        };
+        let report = ResultsReport::new(
+            &results,
+            vec![QuestionResult {
+                question: "2+2?".to_string(),
+                source: Some("math.json".to_string()),
+                chosen: "4".to_string(),
+                correct: "4".to_string(),
+                is_correct: true,
+            }],
+        );
+
+        let json = serde_json::to_string(&report).unwrap();
+        assert!(json.contains("\"percent\":50"));
+        assert!(json.contains("\"is_correct\":true"));
+        assert!(json.contains("\"source\":\"math.json\""));
+    }
+
+    #[test]
+    fn test_get_session_args_from_args() {
+        let mut args = vec!["script".to_string(), "filename.json".to_string()];
+        let session_args = get_session_args_from_args(&mut args).unwrap();
+        assert_eq!(session_args.log_path, None);
+        assert!(!session_args.resuming);
+
+        let mut args = vec![
+            "script".to_string(),
+            "--log".to_string(),
+            "session.log".to_string(),
+            "filename.json".to_string(),
+        ];
+        let session_args = get_session_args_from_args(&mut args).unwrap();
+        assert_eq!(session_args.log_path, Some("session.log".to_string()));
+        assert!(!session_args.resuming);
+        assert_eq!(
+            args,
+            vec!["script".to_string(), "filename.json".to_string()]
+        );
+
+        let mut args = vec![
+            "script".to_string(),
+            "--resume".to_string(),
+            "session.log".to_string(),
+        ];
+        let session_args = get_session_args_from_args(&mut args).unwrap();
+        assert_eq!(session_args.log_path, Some("session.log".to_string()));
+        assert!(session_args.resuming);
+
+        let mut args = vec![
+            "script".to_string(),
+            "--log".to_string(),
+            "a.log".to_string(),
+            "--resume".to_string(),
+            "b.log".to_string(),
+        ];
+        assert!(get_session_args_from_args(&mut args).is_err());
+    }
+
+    #[test]
+    fn test_session_log_records_and_resumes() {
+        let path = "test_session_log_records_and_resumes.log";
+        let _ = std::fs::remove_file(path);
+
+        let mut log = SessionLog::start(path).unwrap();
+        log.append(&SessionRecord {
+            question_id: question_id("What is 10+10?"),
+            chosen: "20".to_string(),
+            correct: "20".to_string(),
+            is_correct: true,
+        })
+        .unwrap();
+        log.append(&SessionRecord {
+            question_id: question_id("What is 2+2?"),
+            chosen: "5".to_string(),
+            correct: "4".to_string(),
+            is_correct: false,
+        })
+        .unwrap();
+
+        let records = SessionLog::load(path).unwrap();
+        let answered: HashSet<u64> = records.iter().map(|record| record.question_id).collect();
+        assert_eq!(records.iter().filter(|record| record.is_correct).count(), 1);
+        assert_eq!(
+            records.iter().filter(|record| !record.is_correct).count(),
+            1
+        );
+        assert!(answered.contains(&question_id("What is 10+10?")));
+        assert!(answered.contains(&question_id("What is 2+2?")));
+        assert!(!answered.contains(&question_id("What is 3+3?")));
+
+        let mut resumed = SessionLog::resume(path).unwrap();
+        resumed
+            .append(&SessionRecord {
+                question_id: question_id("What is 3+3?"),
+                chosen: "6".to_string(),
+                correct: "6".to_string(),
+                is_correct: true,
+            })
+            .unwrap();
+
+        let records = SessionLog::load(path).unwrap();
+        let answered: HashSet<u64> = records.iter().map(|record| record.question_id).collect();
+        assert_eq!(records.iter().filter(|record| record.is_correct).count(), 2);
+        assert_eq!(
+            records.iter().filter(|record| !record.is_correct).count(),
+            1
+        );
+        assert!(answered.contains(&question_id("What is 3+3?")));
+
+        std::fs::remove_file(path).unwrap();
+    }
+
+    #[test]
+    fn test_results_report_consistent_after_resume() {
+        // Reproduces a resumed session: one record already in the log, one
+        // question answered this run. `correct + incorrect` must equal the
+        // number of entries in `questions` so the report is internally
+        // consistent regardless of whether the run was resumed.
+        let path = "test_results_report_consistent_after_resume.log";
+        let _ = std::fs::remove_file(path);
+
+        let mut log = SessionLog::start(path).unwrap();
+        log.append(&SessionRecord {
+            question_id: question_id("What is 10+10?"),
+            chosen: "20".to_string(),
+            correct: "20".to_string(),
+            is_correct: true,
+        })
+        .unwrap();
+
+        let records = SessionLog::load(path).unwrap();
+        let mut results = Results::default();
+        let mut question_results = Vec::new();
+        for record in records {
+            if record.is_correct {
+                results.correct += 1;
+            } else {
+                results.incorrect += 1;
+            }
+            question_results.push(QuestionResult {
+                question: "What is 10+10?".to_string(),
+                source: None,
+                chosen: record.chosen,
+                correct: record.correct,
+                is_correct: record.is_correct,
+            });
+        }
+
+        // The question answered in "this run".
+        results.incorrect += 1;
+        question_results.push(QuestionResult {
+            question: "What is 2+2?".to_string(),
+            source: None,
+            chosen: "5".to_string(),
+            correct: "4".to_string(),
+            is_correct: false,
+        });
+
+        let report = ResultsReport::new(&results, question_results);
+        assert_eq!(report.correct + report.incorrect, report.questions.len());
+
+        std::fs::remove_file(path).unwrap();
+    }
+
     #[test]
     fn test_get_file_name_from_args() {
         let result =
             get_file_name_from_args(vec!["script".to_string(), "filename.json".to_string()]);
-        assert!(result.is_ok());
-        assert_eq!(result, Ok("filename.json".to_string()));
+        assert_eq!(result, Ok(vec!["filename.json".to_string()]));
 
         let result = get_file_name_from_args(vec![
             "script".to_string(),
-            "filename.json".to_string(),
-            "extra".to_string(),
+            "filename1.json".to_string(),
+            "filename2.json".to_string(),
         ]);
-        assert!(result.is_ok());
-        assert_eq!(result, Ok("filename.json".to_string()));
+        assert_eq!(
+            result,
+            Ok(vec![
+                "filename1.json".to_string(),
+                "filename2.json".to_string()
+            ])
+        );
 
         let result = get_file_name_from_args(vec!["script".to_string()]);
         assert!(!result.is_ok());
     }
+
+    #[test]
+    fn test_loader_merges_multiple_files() {
+        let quiz = Loader::new(vec![
+            "example.json".to_string(),
+            "example.hjson".to_string(),
+        ])
+        .load()
+        .unwrap();
+
+        assert_eq!(quiz.questions.len(), 2);
+        assert_eq!(quiz.questions[0].source.as_deref(), Some("example.json"));
+        assert_eq!(quiz.questions[1].source.as_deref(), Some("example.hjson"));
+    }
+
+    #[test]
+    fn test_loader_aggregates_errors_per_file() {
+        let err = Loader::new(vec![
+            "does_not_exist.txt".to_string(),
+            "also_missing.txt".to_string(),
+        ])
+        .load()
+        .unwrap_err();
+
+        match err {
+            QuizParseError::Multiple(errors) => {
+                assert_eq!(errors.len(), 2);
+                assert_eq!(errors[0].0, "does_not_exist.txt");
+                assert_eq!(errors[1].0, "also_missing.txt");
+            }
+            _ => panic!("expected QuizParseError::Multiple"),
+        }
+    }
 }